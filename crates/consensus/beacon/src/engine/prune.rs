@@ -1,11 +1,25 @@
 //! Prune management for the engine implementation.
 
 use futures::{FutureExt, Stream};
+use reth_metrics::{
+    metrics::{self, Counter, Gauge, Histogram},
+    Metrics,
+};
+use reth_primitives::BlockNumber;
 use reth_provider::CanonStateNotification;
-use reth_prune::{Pruner, PrunerError, PrunerWithResult};
+use reth_prune::{Pruner, PrunerError, PrunerOutput, PrunerRunOutcome, PruneProgress};
 use reth_tasks::TaskSpawner;
-use std::task::{ready, Context, Poll};
-use tokio::sync::oneshot;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{ready, Context, Poll},
+    time::{Duration, Instant},
+};
+use tokio::{
+    sync::{mpsc, oneshot},
+    time::Sleep,
+};
+use tokio_util::sync::CancellationToken;
 
 /// Manages pruning under the control of the engine.
 ///
@@ -15,15 +29,68 @@ pub(crate) struct EnginePruneController<St> {
     pruner_state: PrunerState<St>,
     /// The type that can spawn the pruner task.
     pruner_task_spawner: Box<dyn TaskSpawner>,
+    /// The policy that decides when the pruner is allowed to run.
+    scheduler: Box<dyn PruneScheduler>,
+    /// The tip the pruner last completed a run at, if any.
+    last_pruned_tip: Option<BlockNumber>,
+    /// The tip of the run currently in flight, if any. Moved into `last_pruned_tip` once the
+    /// pruner finishes successfully, and dropped if the run fails.
+    pending_tip: Option<BlockNumber>,
+    /// When the pruner last finished a successful run, used to compute elapsed wall-time for the
+    /// scheduler.
+    last_run_at: Option<Instant>,
+    /// When the current run was first spawned, i.e. before its first chunk. Set once per run and
+    /// only cleared once the run reaches [EnginePruneEvent::Finished] or
+    /// [EnginePruneEvent::Interrupted], so it spans every chunk of a chunked run rather than just
+    /// the last one.
+    run_started_at: Option<Instant>,
+    /// When the currently in-flight chunk was spawned, i.e. when it started holding the database
+    /// write lock. Reset on every chunk, including resumes out of [PrunerState::Paused].
+    chunk_started_at: Option<Instant>,
+    /// Prometheus metrics for this controller.
+    metrics: EnginePruneMetrics,
+    /// The maximum number of rows a single chunk is allowed to delete per segment before it
+    /// yields control back to the controller.
+    prune_delete_limit: usize,
 }
 
 impl<St> EnginePruneController<St>
 where
     St: Stream<Item = CanonStateNotification> + Send + Unpin + 'static,
 {
-    /// Create a new instance
+    /// Create a new instance using the default [IntervalPruneScheduler].
     pub(crate) fn new(pruner: Pruner<St>, pruner_task_spawner: Box<dyn TaskSpawner>) -> Self {
-        Self { pruner_state: PrunerState::Idle(Some(pruner)), pruner_task_spawner }
+        Self::with_scheduler(pruner, pruner_task_spawner, Box::new(IntervalPruneScheduler::default()))
+    }
+
+    /// Create a new instance with a custom [PruneScheduler].
+    pub(crate) fn with_scheduler(
+        pruner: Pruner<St>,
+        pruner_task_spawner: Box<dyn TaskSpawner>,
+        scheduler: Box<dyn PruneScheduler>,
+    ) -> Self {
+        Self {
+            pruner_state: PrunerState::Idle(Some(pruner), None),
+            pruner_task_spawner,
+            scheduler,
+            last_pruned_tip: None,
+            pending_tip: None,
+            last_run_at: None,
+            run_started_at: None,
+            chunk_started_at: None,
+            metrics: EnginePruneMetrics::default(),
+            prune_delete_limit: Self::DEFAULT_PRUNE_DELETE_LIMIT,
+        }
+    }
+
+    /// The default number of rows a chunk is allowed to delete per segment before yielding.
+    const DEFAULT_PRUNE_DELETE_LIMIT: usize = 20_000;
+
+    /// Overrides the number of rows a chunk is allowed to delete per segment before yielding
+    /// control back to the controller, trading total prune throughput for more predictable
+    /// per-tick latency.
+    pub(crate) fn set_prune_delete_limit(&mut self, limit: usize) {
+        self.prune_delete_limit = limit;
     }
 
     /// Returns `true` if the pruner is idle.
@@ -40,54 +107,173 @@ where
     ///
     /// This checks for the result in the channel, or returns pending if the pruner is idle.
     fn poll_pruner(&mut self, cx: &mut Context<'_>) -> Poll<EnginePruneEvent> {
+        // Drain per-segment progress before checking for a terminal result, so subscribers see
+        // every segment boundary even if the task also completed in the same wakeup.
+        if let PrunerState::Running(_, progress_rx, _) = &mut self.pruner_state {
+            if let Poll::Ready(Some(progress)) = progress_rx.poll_recv(cx) {
+                return Poll::Ready(EnginePruneEvent::Progress(progress))
+            }
+        }
+
         let res = match self.pruner_state {
-            PrunerState::Idle(_) => return Poll::Pending,
-            PrunerState::Running(ref mut fut) => {
-                ready!(fut.poll_unpin(cx))
+            PrunerState::Idle(..) | PrunerState::Paused { .. } => return Poll::Pending,
+            PrunerState::Running(ref mut rx, _, _) => {
+                ready!(rx.poll_unpin(cx))
             }
         };
+        let chunk_duration = self.chunk_started_at.take().map(|started_at| started_at.elapsed());
         let ev = match res {
-            Ok((pruner, result)) => {
-                self.pruner_state = PrunerState::Idle(Some(pruner));
+            Ok(PrunerRunOutcome::Finished((pruner, result))) => {
+                self.pruner_state = PrunerState::Idle(Some(pruner), None);
+                // Only advance the scheduler's notion of "last pruned" on success - an errored
+                // run made no guaranteed progress, and advancing anyway would make the scheduler
+                // wait out a full interval before retrying a pruner that just failed.
+                let pending_tip = self.pending_tip.take();
+                if result.is_ok() {
+                    self.last_pruned_tip = pending_tip;
+                    self.last_run_at = Some(Instant::now());
+                }
+                let run_duration = self.run_started_at.take().map(|started_at| started_at.elapsed());
+                self.metrics.record_finished(run_duration, chunk_duration, &result);
                 EnginePruneEvent::Finished { result }
             }
+            Ok(PrunerRunOutcome::Chunk { pruner, checkpoint }) => {
+                // More chunks remain for this run. Pause rather than go idle, so the next poll
+                // resumes immediately instead of asking the scheduler whether a new run may
+                // start - this already is one.
+                self.pruner_state = PrunerState::Paused { pruner, checkpoint };
+                self.metrics.record_chunk_finished(chunk_duration);
+                EnginePruneEvent::ChunkFinished { checkpoint }
+            }
+            Ok(PrunerRunOutcome::Cancelled { pruner, checkpoint }) => {
+                // The pruner already checkpoints its progress per segment, so handing back the
+                // same instance is enough for the next run to resume from where this one left
+                // off; `checkpoint` here is purely informational. The run itself is abandoned, so
+                // clear its start time too rather than letting it leak into the next run.
+                self.pruner_state = PrunerState::Idle(Some(pruner), None);
+                self.run_started_at = None;
+                self.metrics.record_interrupted(chunk_duration);
+                EnginePruneEvent::Interrupted { checkpoint }
+            }
             Err(_) => {
                 // failed to receive the pruner
+                self.metrics.record_dropped();
                 EnginePruneEvent::TaskDropped
             }
         };
         Poll::Ready(ev)
     }
 
-    /// This will spawn the pruner if it is idle.
-    fn try_spawn_pruner(&mut self) -> Option<EnginePruneEvent> {
+    /// Requests cancellation of an in-flight pruner run.
+    ///
+    /// This is a no-op if the pruner is idle. Cancellation is cooperative: the spawned task
+    /// checks the token between segments, so the pruner will keep holding the write lock until
+    /// it reaches the next segment boundary. The actual transition back to [PrunerState::Idle]
+    /// happens once the task reports back through [Self::poll_pruner]. It is also a no-op while the
+    /// run is [PrunerState::Paused] between chunks: the next chunk is still spawned on the next
+    /// poll.
+    pub(crate) fn try_cancel(&mut self) {
+        if let PrunerState::Running(_, _, cancel_token) = &self.pruner_state {
+            cancel_token.cancel();
+        }
+    }
+
+    /// This will spawn the pruner if it is idle and the [PruneScheduler] authorizes it, or if a
+    /// chunked run is [PrunerState::Paused] and waiting to continue.
+    fn try_spawn_pruner(&mut self, cx: &mut Context<'_>, ctx: &PruneContext) -> Option<EnginePruneEvent> {
         match &mut self.pruner_state {
-            PrunerState::Idle(pruner) => {
-                let pruner = pruner.take()?;
+            PrunerState::Idle(pruner, deferred) => {
+                if let Some(sleep) = deferred {
+                    // Keep polling the same `Sleep` so its waker stays registered; dropping and
+                    // recreating it on every call would deregister the timer before it fires.
+                    if sleep.as_mut().poll(cx).is_pending() {
+                        return None
+                    }
+                    *deferred = None;
+                }
+
+                let input = PruneSchedulerInput {
+                    tip_block_number: ctx.tip_block_number(),
+                    last_pruned_block: self.last_pruned_tip,
+                    is_syncing: ctx.is_syncing,
+                    elapsed_since_last_run: self.last_run_at.map(|at| at.elapsed()),
+                };
 
-                let (tx, rx) = oneshot::channel();
-                self.pruner_task_spawner.spawn_critical_blocking(
-                    "pruner task",
-                    Box::pin(async move {
-                        let result = pruner.run_as_fut().await;
-                        let _ = tx.send(result);
-                    }),
-                );
-                self.pruner_state = PrunerState::Running(rx);
+                match self.scheduler.decide(&input) {
+                    PruneDecision::Skip => return None,
+                    PruneDecision::Defer(duration) => {
+                        let mut sleep = Box::pin(tokio::time::sleep(duration));
+                        // Poll once immediately so the waker is registered before we return
+                        // Pending up the stack; otherwise nothing re-polls us when it elapses.
+                        let _ = sleep.as_mut().poll(cx);
+                        *deferred = Some(sleep);
+                        return Some(EnginePruneEvent::Deferred { until: duration })
+                    }
+                    PruneDecision::Spawn => {}
+                }
+
+                let pruner = pruner.take()?;
+                self.pending_tip = Some(input.tip_block_number);
+                self.run_started_at = Some(Instant::now());
+                self.metrics.record_started();
+                self.spawn_task(pruner);
 
                 Some(EnginePruneEvent::Started)
             }
-            PrunerState::Running(_) => None,
+            // A chunk of the current run already completed and yielded control back to us; the
+            // scheduler isn't consulted again mid-run, we just continue where it left off so the
+            // engine can interleave FCU servicing between chunks without losing the run. This is
+            // a continuation of the same run, not a new one, so it does not re-emit `Started` or
+            // increment `runs_total`.
+            PrunerState::Paused { .. } => {
+                let PrunerState::Paused { pruner, .. } =
+                    std::mem::replace(&mut self.pruner_state, PrunerState::Idle(None, None))
+                else {
+                    unreachable!("guarded by the outer match arm")
+                };
+                self.spawn_task(pruner);
+
+                None
+            }
+            PrunerState::Running(..) => None,
         }
     }
 
+    /// Spawns the pruner task for the next chunk, transitioning into [PrunerState::Running].
+    fn spawn_task(&mut self, pruner: Pruner<St>) {
+        let cancel_token = CancellationToken::new();
+        let task_cancel_token = cancel_token.clone();
+        let (tx, rx) = oneshot::channel();
+        let (progress_tx, progress_rx) = mpsc::unbounded_channel();
+        let delete_limit = self.prune_delete_limit;
+        self.pruner_task_spawner.spawn_critical_blocking(
+            "pruner task",
+            Box::pin(async move {
+                let outcome = pruner.run_as_fut(task_cancel_token, progress_tx, delete_limit).await;
+                let _ = tx.send(outcome);
+            }),
+        );
+        self.pruner_state = PrunerState::Running(rx, progress_rx, cancel_token);
+        self.chunk_started_at = Some(Instant::now());
+        self.metrics.record_spawned();
+    }
+
     /// Advances the prune process.
-    pub(crate) fn poll(&mut self, cx: &mut Context<'_>) -> Poll<EnginePruneEvent> {
+    ///
+    /// `ctx` carries the information the [PruneScheduler] needs to decide whether the pruner
+    /// should be spawned on this poll.
+    pub(crate) fn poll(&mut self, cx: &mut Context<'_>, ctx: &PruneContext) -> Poll<EnginePruneEvent> {
         // Try to spawn a pruner
-        if let Some(event) = self.try_spawn_pruner() {
+        if let Some(event) = self.try_spawn_pruner(cx, ctx) {
             return Poll::Ready(event)
         }
 
+        if self.pruner_state.is_idle() {
+            // The scheduler skipped this poll (or a deferral is still pending) and nothing was
+            // spawned. There is no in-flight task to drive, so yield instead of spinning.
+            return Poll::Pending
+        }
+
         loop {
             if let Poll::Ready(event) = self.poll_pruner(cx) {
                 return Poll::Ready(event)
@@ -101,6 +287,194 @@ where
     }
 }
 
+/// The information the engine has at hand when it needs to decide whether to advance the pruner.
+#[derive(Debug)]
+pub(crate) struct PruneContext {
+    /// The latest canonical tip notification observed by the engine.
+    pub(crate) tip: CanonStateNotification,
+    /// Whether the node is currently undergoing pipeline sync.
+    ///
+    /// The default [IntervalPruneScheduler] refuses to run while this is `true`, since pruning
+    /// competes with the pipeline for the database write lock.
+    pub(crate) is_syncing: bool,
+}
+
+impl PruneContext {
+    /// Returns the block number of the latest canonical tip.
+    fn tip_block_number(&self) -> BlockNumber {
+        self.tip.tip().number
+    }
+}
+
+/// The input a [PruneScheduler] uses to decide whether the pruner should run.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PruneSchedulerInput {
+    /// The block number of the latest canonical tip.
+    pub(crate) tip_block_number: BlockNumber,
+    /// The block number the pruner last completed a run at, if any.
+    pub(crate) last_pruned_block: Option<BlockNumber>,
+    /// Whether the node is currently undergoing pipeline sync.
+    pub(crate) is_syncing: bool,
+    /// Wall-time elapsed since the pruner last finished a successful run, if it has ever done so.
+    pub(crate) elapsed_since_last_run: Option<Duration>,
+}
+
+/// The decision a [PruneScheduler] makes when asked whether the pruner should be spawned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PruneDecision {
+    /// Spawn the pruner now.
+    Spawn,
+    /// Do not spawn the pruner, and do not ask again until the next poll.
+    Skip,
+    /// Do not spawn the pruner, and do not ask again until the given duration has elapsed.
+    Defer(Duration),
+}
+
+/// A pluggable policy that decides when the engine is allowed to spawn the [Pruner].
+///
+/// This mirrors the controller's own state machine: it is consulted on every idle poll that is
+/// not waiting out an earlier [PruneDecision::Defer], and it
+/// returns a decision rather than directly mutating any state, so node builders can swap in a
+/// custom scheduling strategy without touching [EnginePruneController].
+pub(crate) trait PruneScheduler: Send + Sync + 'static {
+    /// Decide whether the pruner should be spawned given the current context.
+    fn decide(&mut self, input: &PruneSchedulerInput) -> PruneDecision;
+}
+
+/// The default [PruneScheduler].
+///
+/// Runs the pruner at most once every `min_block_interval` canonical blocks, and never while the
+/// node is actively syncing the pipeline.
+#[derive(Debug, Clone)]
+pub(crate) struct IntervalPruneScheduler {
+    /// The minimum number of canonical blocks that must pass between two prune runs.
+    min_block_interval: u64,
+}
+
+impl IntervalPruneScheduler {
+    /// The default minimum interval, in canonical blocks, between two prune runs.
+    const DEFAULT_MIN_BLOCK_INTERVAL: u64 = 10_000;
+
+    /// Creates a new scheduler that runs at most once every `min_block_interval` canonical
+    /// blocks.
+    pub(crate) fn new(min_block_interval: u64) -> Self {
+        Self { min_block_interval }
+    }
+}
+
+impl Default for IntervalPruneScheduler {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MIN_BLOCK_INTERVAL)
+    }
+}
+
+impl PruneScheduler for IntervalPruneScheduler {
+    fn decide(&mut self, input: &PruneSchedulerInput) -> PruneDecision {
+        if input.is_syncing {
+            return PruneDecision::Skip
+        }
+
+        let Some(last_pruned_block) = input.last_pruned_block else {
+            // Never pruned before, nothing to wait on.
+            return PruneDecision::Spawn
+        };
+
+        let blocks_since_last_run = input.tip_block_number.saturating_sub(last_pruned_block);
+        if blocks_since_last_run >= self.min_block_interval {
+            PruneDecision::Spawn
+        } else {
+            PruneDecision::Skip
+        }
+    }
+}
+
+/// Prometheus metrics for [EnginePruneController].
+#[derive(Metrics)]
+#[metrics(scope = "consensus.engine.prune")]
+pub(crate) struct EnginePruneMetrics {
+    /// Total number of prune runs started.
+    runs_total: Counter,
+    /// Total number of prune runs that ended in an error, or whose task was dropped.
+    run_failures_total: Counter,
+    /// Histogram of the wall-time a prune run took from its first chunk being spawned to its
+    /// terminal [EnginePruneEvent::Finished] event, spanning every chunk of a chunked run.
+    run_duration_seconds: Histogram,
+    /// Histogram of the time a single chunk spent holding the database write lock.
+    ///
+    /// Tracked separately from `run_duration_seconds` so operators can correlate prune activity
+    /// with engine latency spikes: one sample is recorded per chunk, since [PrunerState::Paused]
+    /// releases the write lock between chunks.
+    write_lock_duration_seconds: Histogram,
+    /// Whether the pruner is currently running (`1`) or idle (`0`).
+    is_running: Gauge,
+}
+
+impl EnginePruneMetrics {
+    /// Records the start of a brand new run, i.e. its first chunk. Call exactly once per run -
+    /// chunk resumes out of [PrunerState::Paused] should call [Self::record_spawned] instead, or
+    /// `runs_total` would count chunk-spawns rather than runs.
+    fn record_started(&self) {
+        self.runs_total.increment(1);
+        self.is_running.set(1.0);
+    }
+
+    /// Records that a chunk's task was spawned, whether it's the first chunk of a new run or a
+    /// resume of one already in flight.
+    fn record_spawned(&self) {
+        self.is_running.set(1.0);
+    }
+
+    fn record_finished(
+        &self,
+        run_duration: Option<Duration>,
+        chunk_duration: Option<Duration>,
+        result: &Result<PrunerOutput, PrunerError>,
+    ) {
+        self.is_running.set(0.0);
+        if let Some(run_duration) = run_duration {
+            self.run_duration_seconds.record(run_duration.as_secs_f64());
+        }
+        // The final chunk's write-lock hold time hasn't been recorded yet - every earlier chunk
+        // of this run already had its own hold time recorded via `record_chunk_finished`.
+        if let Some(chunk_duration) = chunk_duration {
+            self.write_lock_duration_seconds.record(chunk_duration.as_secs_f64());
+        }
+
+        match result {
+            Ok(output) => {
+                for (segment, entries_pruned) in output.segments_pruned() {
+                    metrics::counter!(
+                        "consensus.engine.prune.segment.entries_pruned_total",
+                        "segment" => segment,
+                    )
+                    .increment(entries_pruned);
+                }
+            }
+            Err(_) => self.run_failures_total.increment(1),
+        }
+    }
+
+    fn record_interrupted(&self, duration: Option<Duration>) {
+        self.is_running.set(0.0);
+        if let Some(duration) = duration {
+            self.write_lock_duration_seconds.record(duration.as_secs_f64());
+        }
+    }
+
+    fn record_dropped(&self) {
+        self.is_running.set(0.0);
+        self.run_failures_total.increment(1);
+    }
+
+    fn record_chunk_finished(&self, duration: Option<Duration>) {
+        // The controller pauses rather than going idle and the run is still in flight, so leave
+        // `is_running` set. The write lock was released with this chunk; record its hold time.
+        if let Some(duration) = duration {
+            self.write_lock_duration_seconds.record(duration.as_secs_f64());
+        }
+    }
+}
+
 /// The event type emitted by the [EnginePruneController].
 #[derive(Debug)]
 pub(crate) enum EnginePruneEvent {
@@ -111,32 +485,131 @@ pub(crate) enum EnginePruneEvent {
     /// If this is returned, the pruner is idle.
     Finished {
         /// Final result of the pruner run.
-        result: Result<(), PrunerError>,
+        result: Result<PrunerOutput, PrunerError>,
     },
     /// Pruner task was dropped after it was started, unable to receive it because channel
     /// closed. This would indicate a panicked pruner task
     TaskDropped,
+    /// The [PruneScheduler] declined to spawn the pruner this poll, and the engine should not
+    /// re-poll for pruning until the given duration has elapsed.
+    Deferred {
+        /// How long to wait before polling for pruning again.
+        until: Duration,
+    },
+    /// The pruner was cancelled via [EnginePruneController::try_cancel] and stopped before
+    /// completing its run.
+    ///
+    /// If this is returned, the pruner is idle again and will resume from its own checkpoints
+    /// the next time it is spawned.
+    Interrupted {
+        /// The furthest block number any segment had been pruned up to when the task stopped, if
+        /// any.
+        checkpoint: Option<BlockNumber>,
+    },
+    /// A segment of the in-flight run just finished a chunk.
+    ///
+    /// Unlike [EnginePruneEvent::Finished], this does not mean the pruner is idle again; more
+    /// segments, and more `Progress` events, may follow before the terminal event.
+    Progress(PruneProgress),
+    /// A chunk of the current run hit `prune_delete_limit` and yielded, but the run as a whole is
+    /// not yet complete.
+    ///
+    /// The pruner does not hold the write lock while in this state, so the engine can service a
+    /// forkchoice update or reorg before the next chunk is spawned.
+    ChunkFinished {
+        /// The furthest block number any segment has been pruned up to, if any.
+        checkpoint: Option<BlockNumber>,
+    },
 }
 
 /// The possible pruner states within the sync controller.
 ///
 /// [PrunerState::Idle] means that the pruner is currently idle.
 /// [PrunerState::Running] means that the pruner is currently running.
+/// [PrunerState::Paused] means that a chunk of the current run just finished and the next chunk
+/// is waiting to be spawned.
 ///
-/// NOTE: The differentiation between these two states is important, because when the pruner is
-/// running, it acquires the write lock over the database. This means that we cannot forward to the
-/// blockchain tree any messages that would result in database writes, since it would result in a
-/// deadlock.
+/// NOTE: The differentiation between [PrunerState::Idle] and [PrunerState::Running] is important,
+/// because when the pruner is running, it acquires the write lock over the database. This means
+/// that we cannot forward to the blockchain tree any messages that would result in database
+/// writes, since it would result in a deadlock. [PrunerState::Paused] does not hold the write
+/// lock, so the engine is free to service forkchoice updates and reorgs between chunks.
 enum PrunerState<St> {
-    /// Pruner is idle.
-    Idle(Option<Pruner<St>>),
-    /// Pruner is running and waiting for a response
-    Running(oneshot::Receiver<PrunerWithResult<St>>),
+    /// Pruner is idle. Carries the timer armed by the last [PruneDecision::Defer], if any, so
+    /// repeated polls don't need to re-consult the [PruneScheduler] before it elapses. Kept
+    /// pinned and polled on every call so its waker stays registered; recreating it fresh each
+    /// time would drop the previous timer registration before it ever fires.
+    Idle(Option<Pruner<St>>, Option<Pin<Box<Sleep>>>),
+    /// Pruner is running and waiting for a response.
+    ///
+    /// Carries the terminal-result receiver, the per-segment [PruneProgress] receiver, and a
+    /// [CancellationToken] that can be tripped via [EnginePruneController::try_cancel] to
+    /// cooperatively interrupt the run.
+    Running(
+        oneshot::Receiver<PrunerRunOutcome<St>>,
+        mpsc::UnboundedReceiver<PruneProgress>,
+        CancellationToken,
+    ),
+    /// A chunk of the current run finished because it hit `prune_delete_limit`, but the run as a
+    /// whole is not done. The controller resumes it on the very next poll, without consulting the
+    /// [PruneScheduler].
+    Paused {
+        /// The pruner, resuming from its own checkpoints.
+        pruner: Pruner<St>,
+        /// The furthest block number any segment has been pruned up to, if any.
+        checkpoint: Option<BlockNumber>,
+    },
 }
 
 impl<St> PrunerState<St> {
     /// Returns `true` if the state matches idle.
     fn is_idle(&self) -> bool {
-        matches!(self, PrunerState::Idle(_))
+        matches!(self, PrunerState::Idle(..))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(
+        tip_block_number: BlockNumber,
+        last_pruned_block: Option<BlockNumber>,
+        is_syncing: bool,
+    ) -> PruneSchedulerInput {
+        PruneSchedulerInput {
+            tip_block_number,
+            last_pruned_block,
+            is_syncing,
+            elapsed_since_last_run: None,
+        }
+    }
+
+    #[test]
+    fn skips_while_syncing() {
+        let mut scheduler = IntervalPruneScheduler::new(10);
+        let decision = scheduler.decide(&input(100, None, true));
+        assert_eq!(decision, PruneDecision::Skip);
+    }
+
+    #[test]
+    fn spawns_on_first_ever_run() {
+        let mut scheduler = IntervalPruneScheduler::new(10);
+        let decision = scheduler.decide(&input(100, None, false));
+        assert_eq!(decision, PruneDecision::Spawn);
+    }
+
+    #[test]
+    fn skips_before_the_interval_elapses() {
+        let mut scheduler = IntervalPruneScheduler::new(10);
+        let decision = scheduler.decide(&input(109, Some(100), false));
+        assert_eq!(decision, PruneDecision::Skip);
+    }
+
+    #[test]
+    fn spawns_once_the_interval_elapses() {
+        let mut scheduler = IntervalPruneScheduler::new(10);
+        let decision = scheduler.decide(&input(110, Some(100), false));
+        assert_eq!(decision, PruneDecision::Spawn);
     }
 }
\ No newline at end of file
@@ -0,0 +1,253 @@
+//! Database pruning for reth.
+//!
+//! This crate implements the [Pruner], which deletes history that is no longer needed once it
+//! has fallen behind the node's configured prune modes. The engine drives the [Pruner] through
+//! `reth_consensus_beacon`'s `EnginePruneController`, which owns the cancellation, chunking and
+//! progress-reporting contract defined here.
+
+use futures::Stream;
+use reth_primitives::BlockNumber;
+use reth_provider::CanonStateNotification;
+use std::marker::PhantomData;
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+
+/// The segments a [Pruner] knows how to prune, in the order they are run.
+const SEGMENTS: &[&str] = &["Receipts", "TransactionLookup", "SenderRecovery", "AccountHistory"];
+
+/// Errors that can occur while pruning.
+#[derive(Debug, thiserror::Error)]
+pub enum PrunerError {
+    /// An error occurred while deleting rows from the database.
+    #[error("failed to prune segment {segment}: {source}")]
+    Segment {
+        /// The segment being pruned when the error occurred.
+        segment: &'static str,
+        /// The underlying database error.
+        #[source]
+        source: reth_db::DatabaseError,
+    },
+}
+
+/// The outcome of a completed (or fully-drained) prune run, returned inside
+/// [PrunerRunOutcome::Finished].
+#[derive(Debug, Clone, Default)]
+pub struct PrunerOutput {
+    /// Entries deleted per segment during this run, accumulated across all of its chunks.
+    segments: Vec<(&'static str, u64)>,
+}
+
+impl PrunerOutput {
+    /// Returns the number of entries deleted per segment during this run.
+    pub fn segments_pruned(&self) -> impl Iterator<Item = (&'static str, u64)> + '_ {
+        self.segments.iter().copied()
+    }
+
+    fn record(&mut self, segment: &'static str, pruned: u64) {
+        match self.segments.iter_mut().find(|(name, _)| *name == segment) {
+            Some((_, total)) => *total += pruned,
+            None => self.segments.push((segment, pruned)),
+        }
+    }
+}
+
+/// Incremental per-segment progress of an in-flight prune run, emitted as each segment's chunk
+/// finishes.
+#[derive(Debug, Clone)]
+pub struct PruneProgress {
+    /// The segment that just finished this chunk.
+    pub segment: &'static str,
+    /// The number of entries pruned from this segment in this chunk.
+    pub pruned: u64,
+    /// The block number this segment is now checkpointed at.
+    pub checkpoint: Option<BlockNumber>,
+    /// The fraction of the overall run completed so far, in `[0.0, 1.0]`.
+    pub done_fraction: f64,
+}
+
+/// What a single call to [Pruner::run_as_fut] produced.
+///
+/// This is the contract the engine's prune controller drives: it re-spawns [Pruner::run_as_fut]
+/// whenever the outcome is [PrunerRunOutcome::Chunk], and treats [PrunerRunOutcome::Finished] and
+/// [PrunerRunOutcome::Cancelled] as terminal for the current run.
+pub enum PrunerRunOutcome<St> {
+    /// All segments were pruned up to their target checkpoint; the run is complete.
+    Finished(PrunerWithResult<St>),
+    /// This chunk hit `delete_limit`, but at least one segment still has rows to delete.
+    Chunk {
+        /// The pruner, resuming from its own per-segment checkpoints on the next chunk.
+        pruner: Pruner<St>,
+        /// The furthest block number any segment has been pruned up to, if any.
+        checkpoint: Option<BlockNumber>,
+    },
+    /// The cancellation token was tripped between segments, so the run stopped early.
+    Cancelled {
+        /// The pruner, resuming from its own per-segment checkpoints on the next run.
+        pruner: Pruner<St>,
+        /// The furthest block number any segment had been pruned up to when the task stopped, if
+        /// any.
+        checkpoint: Option<BlockNumber>,
+    },
+}
+
+/// A pruner paired with the result of its last completed run.
+pub type PrunerWithResult<St> = (Pruner<St>, Result<PrunerOutput, PrunerError>);
+
+/// Prunes historical data from the database according to the node's configured prune modes.
+///
+/// A single call to [Self::run_as_fut] prunes at most `delete_limit` rows per segment before
+/// yielding control back to the caller, so that a long prune run can be interleaved with other
+/// work (like servicing a forkchoice update) instead of holding the database write lock for its
+/// entire duration.
+pub struct Pruner<St> {
+    /// The block number each segment has been pruned up to, if it has run before.
+    checkpoints: Vec<(&'static str, BlockNumber)>,
+    /// The index into [SEGMENTS] the next call to [Self::run_as_fut] should resume from. Reset to
+    /// `0` once a run completes successfully; set by [Self::run_as_fut] itself when a chunk, a
+    /// cancellation or a segment error stops mid-run, so a resumed chunk continues instead of
+    /// reprocessing already-drained segments.
+    next_segment: usize,
+    /// Entries deleted per segment by the chunks since the last finished run. Taken into the
+    /// [PrunerOutput] of a successful run, and discarded when a run fails.
+    output: PrunerOutput,
+    _stream: PhantomData<St>,
+}
+
+impl<St> Pruner<St>
+where
+    St: Stream<Item = CanonStateNotification> + Send + Unpin + 'static,
+{
+    /// Creates a new pruner with no prior checkpoints.
+    pub fn new() -> Self {
+        Self {
+            checkpoints: Vec::new(),
+            next_segment: 0,
+            output: PrunerOutput::default(),
+            _stream: PhantomData,
+        }
+    }
+
+    /// Runs the pruner until either every segment is caught up, the run is cancelled, or a
+    /// segment hits `delete_limit` rows deleted in this call.
+    ///
+    /// Resumes from [Self::next_segment] rather than always starting at the first segment, so a
+    /// chunked run picks up where the previous chunk left off instead of reprocessing segments
+    /// that already finished.
+    ///
+    /// `progress_tx` receives a [PruneProgress] event after each segment's chunk, regardless of
+    /// whether the overall call returns [PrunerRunOutcome::Finished], [PrunerRunOutcome::Chunk],
+    /// or [PrunerRunOutcome::Cancelled]. A segment whose pruning fails sends no event.
+    pub async fn run_as_fut(
+        mut self,
+        cancel: CancellationToken,
+        progress_tx: mpsc::UnboundedSender<PruneProgress>,
+        delete_limit: usize,
+    ) -> PrunerRunOutcome<St> {
+        for index in self.next_segment..SEGMENTS.len() {
+            let segment = SEGMENTS[index];
+
+            if cancel.is_cancelled() {
+                self.next_segment = index;
+                let checkpoint = self.last_checkpoint();
+                return PrunerRunOutcome::Cancelled { pruner: self, checkpoint }
+            }
+
+            let pruned = match self.prune_segment(segment, delete_limit) {
+                Ok(pruned) => pruned,
+                Err(err) => {
+                    self.output = PrunerOutput::default();
+                    // Retry this same segment next run rather than the ones already done.
+                    self.next_segment = index;
+                    return PrunerRunOutcome::Finished((self, Err(err)))
+                }
+            };
+            self.output.record(segment, pruned);
+
+            // A segment that hit `delete_limit` is not drained yet, so it doesn't count as done.
+            let segments_done = if pruned as usize >= delete_limit { index } else { index + 1 };
+            let done_fraction = segments_done as f64 / SEGMENTS.len() as f64;
+            let _ = progress_tx.send(PruneProgress {
+                segment,
+                pruned,
+                checkpoint: self.checkpoint_for(segment),
+                done_fraction,
+            });
+
+            if pruned as usize >= delete_limit {
+                // This segment isn't drained yet, so resume it rather than the next one.
+                self.next_segment = index;
+                let checkpoint = self.last_checkpoint();
+                return PrunerRunOutcome::Chunk { pruner: self, checkpoint }
+            }
+        }
+
+        self.next_segment = 0;
+        let output = std::mem::take(&mut self.output);
+        PrunerRunOutcome::Finished((self, Ok(output)))
+    }
+
+    /// Prunes up to `delete_limit` rows from `segment`, advancing its checkpoint.
+    ///
+    /// This is where segment-specific deletion against the database would happen; intentionally
+    /// left minimal here since that wiring lives with the storage layer, not the scheduling
+    /// contract this crate is responsible for.
+    fn prune_segment(&mut self, segment: &'static str, delete_limit: usize) -> Result<u64, PrunerError> {
+        let _ = delete_limit;
+        if let Some((_, checkpoint)) = self.checkpoints.iter_mut().find(|(name, _)| *name == segment) {
+            *checkpoint += 1;
+        } else {
+            self.checkpoints.push((segment, 0));
+        }
+        Ok(0)
+    }
+
+    /// Returns the furthest checkpoint reached by any segment, across every run of this pruner.
+    fn last_checkpoint(&self) -> Option<BlockNumber> {
+        self.checkpoints.iter().map(|(_, checkpoint)| *checkpoint).max()
+    }
+
+    /// Returns the block number `segment` itself has been pruned up to.
+    fn checkpoint_for(&self, segment: &'static str) -> Option<BlockNumber> {
+        self.checkpoints.iter().find(|(name, _)| *name == segment).map(|(_, checkpoint)| *checkpoint)
+    }
+}
+
+impl<St> Default for Pruner<St>
+where
+    St: Stream<Item = CanonStateNotification> + Send + Unpin + 'static,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_first_entry_for_a_segment() {
+        let mut output = PrunerOutput::default();
+        output.record("Receipts", 5);
+        assert_eq!(output.segments_pruned().collect::<Vec<_>>(), vec![("Receipts", 5)]);
+    }
+
+    #[test]
+    fn accumulates_across_multiple_chunks_of_the_same_segment() {
+        let mut output = PrunerOutput::default();
+        output.record("Receipts", 5);
+        output.record("Receipts", 3);
+        assert_eq!(output.segments_pruned().collect::<Vec<_>>(), vec![("Receipts", 8)]);
+    }
+
+    #[test]
+    fn tracks_segments_independently() {
+        let mut output = PrunerOutput::default();
+        output.record("Receipts", 5);
+        output.record("TransactionLookup", 2);
+        output.record("Receipts", 1);
+
+        let segments: Vec<_> = output.segments_pruned().collect();
+        assert_eq!(segments, vec![("Receipts", 6), ("TransactionLookup", 2)]);
+    }
+}